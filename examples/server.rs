@@ -1,22 +1,123 @@
 // Example integration showing how to use this in a web server or application
+use std::sync::OnceLock;
+
 use boykisser::{
+    DynamicAssetProvider, InMemoryAssetProvider,
     art::{
         get_char_count, get_line_count,
         rust::{RUST_BANNER, RUST_BANNER_INFO, RUST_LOGO, RUST_LOGO_INFO},
     },
     images::{ICON_PNG, ICON_PNG_INFO, LOGO_SVG, LOGO_SVG_INFO},
+    utils::{self, MinifyKind},
 };
 
+/// One entry in the table behind [`serve_asset`]/[`serve_fingerprinted`]: the
+/// plain URL path an asset is served under, its bytes and MIME type, and a
+/// thunk that derives its fingerprinted path from its content hash on
+/// demand. [`serve_asset`] and [`serve_fingerprinted`] both read this one
+/// table instead of separate match statements that could drift out of
+/// sync ([`serve_asset_minified`] still keeps its own per-path match for
+/// minified forms); the fingerprinted path is a thunk
+/// rather than a precomputed `String` so [`serve_asset`]'s common,
+/// non-fingerprinted lookups don't pay for formatting it.
+struct AssetEntry {
+    path: &'static str,
+    bytes: &'static [u8],
+    mime: &'static str,
+    fingerprinted_path: fn() -> String,
+}
+
+/// The assets this example serves, built from the crate's embedded
+/// binary/text constants and their [`AssetInfo::fingerprinted_name`]/
+/// [`TextAssetInfo::fingerprinted_name`].
+fn assets() -> Vec<AssetEntry> {
+    vec![
+        AssetEntry {
+            path: "/icon.png",
+            bytes: ICON_PNG,
+            mime: "image/png",
+            fingerprinted_path: || ICON_PNG_INFO.fingerprinted_name("/icon.png"),
+        },
+        AssetEntry {
+            path: "/logo.svg",
+            bytes: LOGO_SVG,
+            mime: "image/svg+xml",
+            fingerprinted_path: || LOGO_SVG_INFO.fingerprinted_name("/logo.svg"),
+        },
+        AssetEntry {
+            path: "/banner.txt",
+            bytes: RUST_BANNER.as_bytes(),
+            mime: "text/plain",
+            fingerprinted_path: || RUST_BANNER_INFO.fingerprinted_name("/banner.txt"),
+        },
+        AssetEntry {
+            path: "/rust-logo.txt",
+            bytes: RUST_LOGO.as_bytes(),
+            mime: "text/plain",
+            fingerprinted_path: || RUST_LOGO_INFO.fingerprinted_name("/rust-logo.txt"),
+        },
+    ]
+}
+
 /// Example function showing how to serve assets in a web application
 #[must_use]
 pub fn serve_asset(path: &str) -> Option<(Vec<u8>, &'static str)> {
-    match path {
-        "/icon.png" => Some((ICON_PNG.to_vec(), "image/png")),
-        "/logo.svg" => Some((LOGO_SVG.to_vec(), "image/svg+xml")),
-        "/banner.txt" => Some((RUST_BANNER.as_bytes().to_vec(), "text/plain")),
-        "/rust-logo.txt" => Some((RUST_LOGO.as_bytes().to_vec(), "text/plain")),
-        _ => None,
+    assets()
+        .into_iter()
+        .find(|asset| asset.path == path)
+        .map(|asset| (asset.bytes.to_vec(), asset.mime))
+}
+
+/// `Cache-Control` value handed out alongside fingerprinted assets: the
+/// content hash in the URL changes whenever the bytes do, so the response
+/// itself can be cached forever.
+const IMMUTABLE_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// Serve an asset under its fingerprinted path (e.g.
+/// `/icon-1a2b3c4d5e6f7890.png`), returning bytes, MIME type, and an
+/// immutable `Cache-Control` hint once the hash segment is confirmed to
+/// match the asset's current content.
+#[must_use]
+pub fn serve_fingerprinted(path: &str) -> Option<(Vec<u8>, &'static str, &'static str)> {
+    assets()
+        .into_iter()
+        .find(|asset| (asset.fingerprinted_path)() == path)
+        .map(|asset| (asset.bytes.to_vec(), asset.mime, IMMUTABLE_CACHE_CONTROL))
+}
+
+/// Serve an asset by checking `provider` first, then falling back to the
+/// embedded defaults via [`serve_asset`], so a provider can add new paths
+/// or override embedded ones.
+#[must_use]
+pub fn serve_asset_with<P: DynamicAssetProvider>(provider: &P, path: &str) -> Option<(Vec<u8>, String)> {
+    if let Some((data, mime)) = provider.resolve(path) {
+        return Some((data, mime.to_string()));
     }
+    serve_asset(path).map(|(data, mime)| (data, mime.to_string()))
+}
+
+/// Cache of `/logo.svg`'s minified form, computed once on first request.
+static LOGO_SVG_MINIFIED: OnceLock<String> = OnceLock::new();
+
+/// Serve an asset in its minified form. Only paths with an arm (and a
+/// `OnceLock` cache) in the match below are minified, currently just
+/// `/logo.svg`; every other path falls back to [`serve_asset`] unchanged,
+/// even when [`MinifyKind::from_mime_type`] knows its MIME type. The
+/// minified output is computed once and cached.
+#[must_use]
+pub fn serve_asset_minified(path: &str) -> Option<(Vec<u8>, &'static str)> {
+    let (data, mime) = serve_asset(path)?;
+    let Some(kind) = MinifyKind::from_mime_type(mime) else {
+        return Some((data, mime));
+    };
+
+    let minified = match path {
+        "/logo.svg" => LOGO_SVG_MINIFIED
+            .get_or_init(|| utils::minify(std::str::from_utf8(LOGO_SVG).unwrap_or_default(), kind)),
+        _ => return Some((data, mime)),
+    };
+
+    Some((minified.as_bytes().to_vec(), mime))
 }
 
 /// Example showing asset metadata usage
@@ -48,6 +149,32 @@ fn main() {
         println!("Serving /icon.png as {content_type}");
     }
 
+    // Serve the fingerprinted variant with a far-future cache header
+    let icon_path = ICON_PNG_INFO.fingerprinted_name("/icon.png");
+    if let Some((_, content_type, cache_control)) = serve_fingerprinted(&icon_path) {
+        println!("Serving {icon_path} as {content_type} ({cache_control})");
+    }
+
+    // Serve the minified variant of an SVG asset
+    if let Some((bytes, content_type)) = serve_asset_minified("/logo.svg") {
+        println!(
+            "Serving /logo.svg as {content_type}, minified to {} bytes",
+            bytes.len()
+        );
+    }
+
+    // Serve from a dynamic provider, falling back to embedded assets
+    let mut provider = InMemoryAssetProvider::new();
+    if provider.setup().is_ok() {
+        provider.register("/custom.txt", b"hello from disk".to_vec(), "text/plain");
+    }
+    if let Some((_, content_type)) = serve_asset_with(&provider, "/custom.txt") {
+        println!("Serving /custom.txt (dynamic) as {content_type}");
+    }
+    if let Some((_, content_type)) = serve_asset_with(&provider, "/icon.png") {
+        println!("Serving /icon.png (embedded fallback) as {content_type}");
+    }
+
     // Print asset information
     print_asset_info();
 }
@@ -1,4 +1,4 @@
-use crate::{TextAssetInfo, include_binary_asset};
+use crate::{TextAssetInfo, include_binary_asset, utils};
 
 /// Rust logo ASCII art
 #[cfg(feature = "rust")]
@@ -16,6 +16,7 @@ pub static RUST_LOGO: &str = r"
 pub static RUST_LOGO_INFO: TextAssetInfo = TextAssetInfo {
     name: "RUST_LOGO",
     description: "Rust programming language logo in ASCII art",
+    hash: utils::content_hash_hex(RUST_LOGO.as_bytes()),
 };
 
 /// Simple banner
@@ -32,6 +33,7 @@ pub static RUST_BANNER: &str = r"
 pub static RUST_BANNER_INFO: TextAssetInfo = TextAssetInfo {
     name: "RUST_BANNER",
     description: "Welcome banner for Rust applications",
+    hash: utils::content_hash_hex(RUST_BANNER.as_bytes()),
 };
 
 include_binary_asset!(
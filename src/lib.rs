@@ -69,6 +69,8 @@ pub struct AssetInfo {
     pub size: usize,
     pub format: &'static str,
     pub description: &'static str,
+    /// Short content hash (ASCII hex digits), used for cache-busting URLs
+    pub hash: [u8; 16],
 }
 
 /// Metadata for text assets
@@ -76,6 +78,8 @@ pub struct AssetInfo {
 pub struct TextAssetInfo {
     pub name: &'static str,
     pub description: &'static str,
+    /// Short content hash (ASCII hex digits), used for cache-busting URLs
+    pub hash: [u8; 16],
 }
 
 impl fmt::Display for AssetInfo {
@@ -90,6 +94,47 @@ impl fmt::Display for TextAssetInfo {
     }
 }
 
+impl AssetInfo {
+    /// This asset's content hash as a hex string
+    #[must_use]
+    pub fn hash_hex(&self) -> String {
+        self.hash.iter().map(|&b| b as char).collect()
+    }
+
+    /// Inserts this asset's content hash between `plain_name`'s stem and
+    /// extension, e.g. `icon.png` becomes `icon-1a2b3c4d5e6f7890.png`,
+    /// suitable for serving under a far-future `Cache-Control` header.
+    #[must_use]
+    pub fn fingerprinted_name(&self, plain_name: &str) -> String {
+        fingerprint_stem(plain_name, &self.hash_hex())
+    }
+}
+
+impl TextAssetInfo {
+    /// This asset's content hash as a hex string
+    #[must_use]
+    pub fn hash_hex(&self) -> String {
+        self.hash.iter().map(|&b| b as char).collect()
+    }
+
+    /// Inserts this asset's content hash between `plain_name`'s stem and
+    /// extension, e.g. `banner.txt` becomes `banner-1a2b3c4d5e6f7890.txt`,
+    /// suitable for serving under a far-future `Cache-Control` header.
+    #[must_use]
+    pub fn fingerprinted_name(&self, plain_name: &str) -> String {
+        fingerprint_stem(plain_name, &self.hash_hex())
+    }
+}
+
+/// Inserts `hash_hex` between `plain_name`'s stem and extension, e.g.
+/// `icon.png` + `1a2b3c4d5e6f7890` becomes `icon-1a2b3c4d5e6f7890.png`.
+fn fingerprint_stem(plain_name: &str, hash_hex: &str) -> String {
+    plain_name.rsplit_once('.').map_or_else(
+        || format!("{plain_name}-{hash_hex}"),
+        |(stem, ext)| format!("{stem}-{hash_hex}.{ext}"),
+    )
+}
+
 /// Macro to include binary assets with metadata
 ///
 /// # Usage
@@ -114,6 +159,7 @@ macro_rules! include_binary_asset {
                 size: $name.len(),
                 format: $format,
                 description: $desc,
+                hash: $crate::utils::content_hash_hex($name),
             };
         }
     };
@@ -142,6 +188,159 @@ macro_rules! include_text_asset {
             $vis static [<$name _INFO>]: $crate::TextAssetInfo = $crate::TextAssetInfo {
                 name: stringify!($name),
                 description: $desc,
+                hash: $crate::utils::content_hash_hex($name.as_bytes()),
+            };
+        }
+    };
+}
+
+/// Metadata for image assets, including dimensions validated at compile time
+#[derive(Debug, Clone)]
+pub struct ImageAssetInfo {
+    pub name: &'static str,
+    pub size: usize,
+    pub format: &'static str,
+    pub width: u32,
+    pub height: u32,
+    pub description: &'static str,
+}
+
+impl fmt::Display for ImageAssetInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} bytes, {}x{} ({})",
+            self.name, self.size, self.width, self.height, self.format
+        )
+    }
+}
+
+/// Const-evaluable image header parsing used by [`include_image_asset!`].
+///
+/// Not part of the public API: it exists so the macro can call into it from
+/// a downstream crate via `$crate::image_format`.
+#[doc(hidden)]
+pub mod image_format {
+    /// Validates `data` against the magic bytes for `format` and extracts its
+    /// width/height. Panics if they don't match, which surfaces as a
+    /// compile-time error when called from a `const` initializer.
+    #[must_use]
+    pub const fn dimensions(data: &[u8], format: &str) -> (u32, u32) {
+        match format.as_bytes() {
+            b"PNG" => png_dimensions(data),
+            b"GIF" => gif_dimensions(data),
+            b"ICO" => ico_dimensions(data),
+            _ => panic!("include_image_asset!: format must be \"PNG\", \"GIF\", or \"ICO\""),
+        }
+    }
+
+    const fn png_dimensions(data: &[u8]) -> (u32, u32) {
+        const SIGNATURE: [u8; 8] = [0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A];
+        assert!(
+            data.len() >= 24,
+            "include_image_asset!: file is too small to be a PNG"
+        );
+        let mut i = 0;
+        while i < SIGNATURE.len() {
+            assert!(
+                data[i] == SIGNATURE[i],
+                "include_image_asset!: declared format \"PNG\" but magic bytes do not match"
+            );
+            i += 1;
+        }
+        // IHDR is always the first chunk: width at offset 16, height at offset 20.
+        let width = u32::from_be_bytes([data[16], data[17], data[18], data[19]]);
+        let height = u32::from_be_bytes([data[20], data[21], data[22], data[23]]);
+        (width, height)
+    }
+
+    const fn gif_dimensions(data: &[u8]) -> (u32, u32) {
+        assert!(
+            data.len() >= 10,
+            "include_image_asset!: file is too small to be a GIF"
+        );
+        let is_gif87a = data[0] == b'G'
+            && data[1] == b'I'
+            && data[2] == b'F'
+            && data[3] == b'8'
+            && data[4] == b'7'
+            && data[5] == b'a';
+        let is_gif89a = data[0] == b'G'
+            && data[1] == b'I'
+            && data[2] == b'F'
+            && data[3] == b'8'
+            && data[4] == b'9'
+            && data[5] == b'a';
+        assert!(
+            is_gif87a || is_gif89a,
+            "include_image_asset!: declared format \"GIF\" but magic bytes do not match"
+        );
+        let width = u16::from_le_bytes([data[6], data[7]]) as u32;
+        let height = u16::from_le_bytes([data[8], data[9]]) as u32;
+        (width, height)
+    }
+
+    const fn ico_dimensions(data: &[u8]) -> (u32, u32) {
+        assert!(
+            data.len() >= 22,
+            "include_image_asset!: file is too small to be an ICO"
+        );
+        assert!(
+            data[0] == 0 && data[1] == 0 && data[2] == 1 && data[3] == 0,
+            "include_image_asset!: declared format \"ICO\" but magic bytes do not match"
+        );
+        let count = u16::from_le_bytes([data[4], data[5]]);
+        assert!(count > 0, "include_image_asset!: ICO contains no images");
+        // First directory entry starts right after the 6-byte header.
+        let raw_width = data[6];
+        let raw_height = data[7];
+        let width = if raw_width == 0 { 256 } else { raw_width as u32 };
+        let height = if raw_height == 0 { 256 } else { raw_height as u32 };
+        (width, height)
+    }
+}
+
+/// Macro to include image assets with compile-time format validation and
+/// dimension extraction
+///
+/// Works like [`include_binary_asset!`], but also checks the embedded file's
+/// magic bytes against the declared `format` and extracts its width and
+/// height at build time, so a mismatch (e.g. declaring `"PNG"` for a file
+/// that is actually a JPEG) is caught before the asset ever reaches a web
+/// handler. Supported formats are `"PNG"`, `"GIF"`, and `"ICO"`.
+///
+/// A mismatch surfaces as a `const`-eval panic (E0080) rather than a clean
+/// `compile_error!`, since `macro_rules!` can't branch on the bytes a path
+/// points to at parse time — the diagnostic is noisier but still a hard
+/// build failure.
+///
+/// # Usage
+/// ```ignore
+/// use boykisser::include_image_asset;
+///
+/// include_image_asset!(
+///     pub ICON_PNG,           // Asset name
+///     "assets/icon.png",      // File path
+///     "PNG",                  // Format
+///     "Application icon"      // Description
+/// );
+/// ```
+#[macro_export]
+macro_rules! include_image_asset {
+    ($vis:vis $name:ident, $path:expr, $format:expr, $desc:expr) => {
+        $vis static $name: &'static [u8] = include_bytes!($path);
+
+        paste::paste! {
+            const [<$name _DIMENSIONS>]: (u32, u32) =
+                $crate::image_format::dimensions($name, $format);
+
+            $vis static [<$name _INFO>]: $crate::ImageAssetInfo = $crate::ImageAssetInfo {
+                name: stringify!($name),
+                size: $name.len(),
+                format: $format,
+                width: [<$name _DIMENSIONS>].0,
+                height: [<$name _DIMENSIONS>].1,
+                description: $desc,
             };
         }
     };
@@ -149,6 +348,12 @@ macro_rules! include_text_asset {
 
 /// Macro to create an asset registry with multiple assets
 ///
+/// Besides the compile-time constants and `list_*` functions, this also
+/// generates a zero-sized `Registry` type implementing [`AssetRetriever`],
+/// so assets can be looked up by name at runtime (e.g. when the name
+/// arrives as a string in a request handler) instead of requiring a
+/// hand-written match.
+///
 /// # Usage
 /// ```ignore
 /// use boykisser::create_asset_registry;
@@ -164,6 +369,10 @@ macro_rules! include_text_asset {
 ///         (CONFIG_TEMPLATE, "assets/config.toml", "Default configuration"),
 ///     ]
 /// );
+///
+/// // Resolve an asset by name at runtime
+/// use boykisser::AssetRetriever;
+/// let icon = MyAssets::Registry.get_binary_asset("ICON_SVG");
 /// ```
 #[macro_export]
 macro_rules! create_asset_registry {
@@ -184,7 +393,12 @@ macro_rules! create_asset_registry {
             )*
 
             /// List all binary assets in this registry
-            #[must_use] pub const fn list_binary_assets() -> Vec<&'static $crate::AssetInfo> {
+            // Not `const`: with at least one asset, `vec![...]` pulls in
+            // allocator calls that aren't const-evaluable on this toolchain,
+            // and that depends on which registry this macro expands for.
+            #[must_use]
+            #[allow(clippy::missing_const_for_fn)]
+            pub fn list_binary_assets() -> Vec<&'static $crate::AssetInfo> {
                 vec![
                     $(
                         paste::paste! { &[<$bin_name _INFO>] },
@@ -193,13 +407,44 @@ macro_rules! create_asset_registry {
             }
 
             /// List all text assets in this registry
-            #[must_use] pub const fn list_text_assets() -> Vec<&'static $crate::TextAssetInfo> {
+            #[must_use]
+            #[allow(clippy::missing_const_for_fn)]
+            pub fn list_text_assets() -> Vec<&'static $crate::TextAssetInfo> {
                 vec![
                     $(
                         paste::paste! { &[<$text_name _INFO>] },
                     )*
                 ]
             }
+
+            /// Zero-sized handle for resolving this registry's assets by
+            /// name at runtime, e.g. when the asset name arrives as a
+            /// string from a request handler
+            #[derive(Debug, Clone, Copy, Default)]
+            pub struct Registry;
+
+            impl $crate::AssetRetriever for Registry {
+                fn get_binary_asset(&self, name: &str) -> Option<&'static [u8]> {
+                    match name {
+                        $( stringify!($bin_name) => Some($bin_name), )*
+                        _ => None,
+                    }
+                }
+
+                fn get_text_asset(&self, name: &str) -> Option<&'static str> {
+                    match name {
+                        $( stringify!($text_name) => Some($text_name), )*
+                        _ => None,
+                    }
+                }
+
+                fn list_assets(&self) -> Vec<String> {
+                    vec![
+                        $( stringify!($bin_name).to_string(), )*
+                        $( stringify!($text_name).to_string(), )*
+                    ]
+                }
+            }
         }
     };
 }
@@ -211,6 +456,80 @@ pub trait AssetRetriever {
     fn list_assets(&self) -> Vec<String>;
 }
 
+/// Error returned by [`DynamicAssetProvider::setup`]
+#[derive(Debug, Clone)]
+pub struct AssetError(String);
+
+impl AssetError {
+    #[must_use]
+    pub fn new(message: impl Into<String>) -> Self {
+        Self(message.into())
+    }
+}
+
+impl fmt::Display for AssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for AssetError {}
+
+/// A runtime-swappable source of assets that layers over the compile-time registry.
+///
+/// Applications can register or override assets at startup (e.g. loading
+/// user-supplied art from disk) while the embedded defaults remain
+/// available as a fallback. See the `server` example's `serve_asset_with`
+/// for how a provider chain is built on top of this.
+pub trait DynamicAssetProvider {
+    /// Run once before first use, to populate the provider (e.g. read a
+    /// directory, decode files, populate an in-memory map)
+    ///
+    /// # Errors
+    /// Returns an [`AssetError`] if the provider could not populate itself.
+    fn setup(&mut self) -> Result<(), AssetError>;
+
+    /// Resolve `path` to its bytes and MIME type, if this provider has it
+    fn resolve(&self, path: &str) -> Option<(Vec<u8>, &str)>;
+}
+
+/// Default in-memory [`DynamicAssetProvider`]: assets are added with
+/// [`InMemoryAssetProvider::register`] and resolved by exact path match.
+/// Its [`DynamicAssetProvider::setup`] does nothing and always succeeds.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryAssetProvider {
+    assets: std::collections::HashMap<String, (Vec<u8>, String)>,
+}
+
+impl InMemoryAssetProvider {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register or override the asset served at `path`
+    pub fn register(
+        &mut self,
+        path: impl Into<String>,
+        data: Vec<u8>,
+        mime_type: impl Into<String>,
+    ) {
+        self.assets.insert(path.into(), (data, mime_type.into()));
+    }
+}
+
+impl DynamicAssetProvider for InMemoryAssetProvider {
+    fn setup(&mut self) -> Result<(), AssetError> {
+        Ok(())
+    }
+
+    fn resolve(&self, path: &str) -> Option<(Vec<u8>, &str)> {
+        self.assets
+            .get(path)
+            .map(|(data, mime)| (data.clone(), mime.as_str()))
+    }
+}
+
 pub mod images {
     use super::AssetInfo;
 
@@ -231,6 +550,7 @@ pub mod images {
         size: ICON_PNG.len(),
         format: "PNG",
         description: "Application icon in PNG format",
+        hash: super::utils::content_hash_hex(ICON_PNG),
     };
 
     /// Placeholder for SVG logo
@@ -241,6 +561,7 @@ pub mod images {
         size: LOGO_SVG.len(),
         format: "SVG",
         description: "Company logo in SVG format",
+        hash: super::utils::content_hash_hex(LOGO_SVG),
     };
 }
 
@@ -262,6 +583,40 @@ create_asset_registry!(
 /// Utility functions for working with assets
 pub mod utils {
 
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    /// FNV-1a 64-bit hash, picked for being a fast, const-evaluable,
+    /// zero-dependency way to fingerprint asset bytes.
+    const fn fnv1a64(data: &[u8]) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let mut hash = FNV_OFFSET;
+        let mut i = 0;
+        while i < data.len() {
+            hash ^= data[i] as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+            i += 1;
+        }
+        hash
+    }
+
+    /// Short content hash of `data`, as ASCII hex digits, for use in
+    /// cache-busting URLs (see [`crate::AssetInfo::fingerprinted_name`]).
+    #[must_use]
+    pub const fn content_hash_hex(data: &[u8]) -> [u8; 16] {
+        let digest = fnv1a64(data);
+        let mut out = [0u8; 16];
+        let mut i = 0;
+        while i < 16 {
+            let shift = (15 - i) * 4;
+            let nibble = ((digest >> shift) & 0xF) as u8;
+            out[i] = HEX_DIGITS[nibble as usize];
+            i += 1;
+        }
+        out
+    }
+
     /// Convert binary asset to base64 string (useful for embedding in HTML/CSS)
     #[must_use]
     pub fn to_base64(data: &[u8]) -> String {
@@ -321,12 +676,344 @@ pub mod utils {
     pub fn create_data_url(data: &[u8], mime_type: &str) -> String {
         format!("data:{};base64,{}", mime_type, to_base64(data))
     }
+
+    /// Error returned by [`from_base64`]
+    #[derive(Debug, Clone)]
+    pub struct Base64Error(String);
+
+    impl Base64Error {
+        #[must_use]
+        pub fn new(message: impl Into<String>) -> Self {
+            Self(message.into())
+        }
+    }
+
+    impl crate::fmt::Display for Base64Error {
+        fn fmt(&self, f: &mut crate::fmt::Formatter<'_>) -> crate::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl std::error::Error for Base64Error {}
+
+    /// Look up the 6-bit value of a base64 alphabet character
+    fn base64_char_value(ch: u8) -> Option<u32> {
+        const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        CHARS
+            .iter()
+            .position(|&c| c == ch)
+            .and_then(|pos| u32::try_from(pos).ok())
+    }
+
+    /// Decode a base64 string back into bytes (inverse of [`to_base64`])
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Base64Error`] if the input length isn't a multiple of four,
+    /// contains a character outside the standard alphabet, or pads in the
+    /// middle of the string instead of only at the end.
+    pub fn from_base64(input: &str) -> Result<Vec<u8>, Base64Error> {
+        let bytes = input.as_bytes();
+        if bytes.is_empty() {
+            return Ok(Vec::new());
+        }
+        if !bytes.len().is_multiple_of(4) {
+            return Err(Base64Error::new("base64 input length must be a multiple of four"));
+        }
+
+        let mut result = Vec::with_capacity(bytes.len() / 4 * 3);
+        let mut padding_seen = false;
+        for chunk in bytes.chunks(4) {
+            if padding_seen {
+                return Err(Base64Error::new("base64 padding must only appear in the final chunk"));
+            }
+
+            let mut pad_count = 0;
+            let mut values = [0u32; 4];
+            for (i, &byte) in chunk.iter().enumerate() {
+                if byte == b'=' {
+                    pad_count += 1;
+                } else if pad_count > 0 {
+                    return Err(Base64Error::new("base64 padding must only appear at the end"));
+                } else {
+                    values[i] = base64_char_value(byte)
+                        .ok_or_else(|| Base64Error::new(format!("invalid base64 character '{}'", byte as char)))?;
+                }
+            }
+            if pad_count > 2 {
+                return Err(Base64Error::new("base64 chunk has too much padding"));
+            }
+            padding_seen = pad_count > 0;
+
+            let b = (values[0] << 18) | (values[1] << 12) | (values[2] << 6) | values[3];
+            result.push(((b >> 16) & 0xFF) as u8);
+            if pad_count < 2 {
+                result.push(((b >> 8) & 0xFF) as u8);
+            }
+            if pad_count < 1 {
+                result.push((b & 0xFF) as u8);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Parse a data URL back into its MIME type and decoded bytes (inverse of
+    /// [`create_data_url`]). Only the `;base64` encoding is supported.
+    #[must_use]
+    pub fn parse_data_url(url: &str) -> Option<(String, Vec<u8>)> {
+        let rest = url.strip_prefix("data:")?;
+        let (meta, data) = rest.split_once(',')?;
+        let mime_type = meta.strip_suffix(";base64")?;
+        let bytes = from_base64(data).ok()?;
+        Some((mime_type.to_string(), bytes))
+    }
+
+    /// SHA-256 round constants (first 32 bits of the fractional parts of
+    /// the cube roots of the first 64 primes)
+    #[rustfmt::skip]
+    const SHA256_ROUND_CONSTANTS: [u32; 64] = [
+        0x428a_2f98, 0x7137_4491, 0xb5c0_fbcf, 0xe9b5_dba5, 0x3956_c25b, 0x59f1_11f1, 0x923f_82a4, 0xab1c_5ed5,
+        0xd807_aa98, 0x1283_5b01, 0x2431_85be, 0x550c_7dc3, 0x72be_5d74, 0x80de_b1fe, 0x9bdc_06a7, 0xc19b_f174,
+        0xe49b_69c1, 0xefbe_4786, 0x0fc1_9dc6, 0x240c_a1cc, 0x2de9_2c6f, 0x4a74_84aa, 0x5cb0_a9dc, 0x76f9_88da,
+        0x983e_5152, 0xa831_c66d, 0xb003_27c8, 0xbf59_7fc7, 0xc6e0_0bf3, 0xd5a7_9147, 0x06ca_6351, 0x1429_2967,
+        0x27b7_0a85, 0x2e1b_2138, 0x4d2c_6dfc, 0x5338_0d13, 0x650a_7354, 0x766a_0abb, 0x81c2_c92e, 0x9272_2c85,
+        0xa2bf_e8a1, 0xa81a_664b, 0xc24b_8b70, 0xc76c_51a3, 0xd192_e819, 0xd699_0624, 0xf40e_3585, 0x106a_a070,
+        0x19a4_c116, 0x1e37_6c08, 0x2748_774c, 0x34b0_bcb5, 0x391c_0cb3, 0x4ed8_aa4a, 0x5b9c_ca4f, 0x682e_6ff3,
+        0x748f_82ee, 0x78a5_636f, 0x84c8_7814, 0x8cc7_0208, 0x90be_fffa, 0xa450_6ceb, 0xbef9_a3f7, 0xc671_78f2,
+    ];
+
+    /// Hand-rolled SHA-256 (for demonstration - use a proper crypto crate in production)
+    fn sha256(data: &[u8]) -> [u8; 32] {
+        let mut state: [u32; 8] = [
+            0x6a09_e667, 0xbb67_ae85, 0x3c6e_f372, 0xa54f_f53a, 0x510e_527f, 0x9b05_688c, 0x1f83_d9ab,
+            0x5be0_cd19,
+        ];
+
+        let bit_len = (data.len() as u64) * 8;
+        let mut message = data.to_vec();
+        message.push(0x80);
+        while message.len() % 64 != 56 {
+            message.push(0);
+        }
+        message.extend_from_slice(&bit_len.to_be_bytes());
+
+        for block in message.chunks(64) {
+            let mut schedule = [0u32; 64];
+            for (idx, word) in schedule.iter_mut().take(16).enumerate() {
+                *word = u32::from_be_bytes([
+                    block[idx * 4],
+                    block[idx * 4 + 1],
+                    block[idx * 4 + 2],
+                    block[idx * 4 + 3],
+                ]);
+            }
+            for idx in 16..64 {
+                let sigma0 = schedule[idx - 15].rotate_right(7)
+                    ^ schedule[idx - 15].rotate_right(18)
+                    ^ (schedule[idx - 15] >> 3);
+                let sigma1 = schedule[idx - 2].rotate_right(17)
+                    ^ schedule[idx - 2].rotate_right(19)
+                    ^ (schedule[idx - 2] >> 10);
+                schedule[idx] = schedule[idx - 16]
+                    .wrapping_add(sigma0)
+                    .wrapping_add(schedule[idx - 7])
+                    .wrapping_add(sigma1);
+            }
+
+            let mut working = state;
+            for idx in 0..64 {
+                let big_sigma1 = working[4].rotate_right(6)
+                    ^ working[4].rotate_right(11)
+                    ^ working[4].rotate_right(25);
+                let choice = (working[4] & working[5]) ^ ((!working[4]) & working[6]);
+                let temp1 = working[7]
+                    .wrapping_add(big_sigma1)
+                    .wrapping_add(choice)
+                    .wrapping_add(SHA256_ROUND_CONSTANTS[idx])
+                    .wrapping_add(schedule[idx]);
+                let big_sigma0 = working[0].rotate_right(2)
+                    ^ working[0].rotate_right(13)
+                    ^ working[0].rotate_right(22);
+                let majority = (working[0] & working[1])
+                    ^ (working[0] & working[2])
+                    ^ (working[1] & working[2]);
+                let temp2 = big_sigma0.wrapping_add(majority);
+
+                working[7] = working[6];
+                working[6] = working[5];
+                working[5] = working[4];
+                working[4] = working[3].wrapping_add(temp1);
+                working[3] = working[2];
+                working[2] = working[1];
+                working[1] = working[0];
+                working[0] = temp1.wrapping_add(temp2);
+            }
+
+            for (word, delta) in state.iter_mut().zip(working) {
+                *word = word.wrapping_add(delta);
+            }
+        }
+
+        let mut digest = [0u8; 32];
+        for (idx, word) in state.iter().enumerate() {
+            digest[idx * 4..idx * 4 + 4].copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+
+    /// Content-Security-Policy hash token for `data`, suitable for an
+    /// inline `<style>`/`<script>` block or the bundled content of a
+    /// data-URL SVG, e.g. `sha256-47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=`.
+    #[must_use]
+    pub fn csp_hash(data: &[u8]) -> String {
+        format!("sha256-{}", to_base64(&sha256(data)))
+    }
+
+    /// [`csp_hash`] for each of `assets`, in order, for building a strict
+    /// CSP header that permits exactly the bundled content and nothing else.
+    #[must_use]
+    pub fn csp_hashes_for(assets: &[&[u8]]) -> Vec<String> {
+        assets.iter().map(|data| csp_hash(data)).collect()
+    }
+
+    /// Text-asset kinds [`minify`] knows how to shrink
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum MinifyKind {
+        Css,
+        Js,
+        Json,
+        Svg,
+    }
+
+    impl MinifyKind {
+        /// Picks the right minifier for a MIME type, e.g. one produced by
+        /// [`mime_type_from_extension`]
+        #[must_use]
+        pub fn from_mime_type(mime_type: &str) -> Option<Self> {
+            match mime_type {
+                "text/css" => Some(Self::Css),
+                "application/javascript" => Some(Self::Js),
+                "application/json" => Some(Self::Json),
+                "image/svg+xml" => Some(Self::Svg),
+                _ => None,
+            }
+        }
+
+        const fn strips_block_comments(self) -> bool {
+            matches!(self, Self::Css | Self::Js)
+        }
+
+        const fn strips_markup_comments(self) -> bool {
+            matches!(self, Self::Svg)
+        }
+    }
+
+    /// Minify a text asset: collapses runs of insignificant whitespace
+    /// (including newlines) to a single space and strips comments, without
+    /// touching the contents of quoted strings.
+    #[must_use]
+    pub fn minify(data: &str, kind: MinifyKind) -> String {
+        let chars: Vec<char> = data.chars().collect();
+        let mut out = String::with_capacity(data.len());
+        let mut quote: Option<char> = None;
+        let mut pending_space = false;
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+
+            if let Some(q) = quote {
+                out.push(c);
+                if c == '\\' && i + 1 < chars.len() {
+                    out.push(chars[i + 1]);
+                    i += 2;
+                    continue;
+                }
+                if c == q {
+                    quote = None;
+                }
+                i += 1;
+                continue;
+            }
+
+            if c == '"' || c == '\'' {
+                if pending_space && !out.is_empty() {
+                    out.push(' ');
+                }
+                pending_space = false;
+                quote = Some(c);
+                out.push(c);
+                i += 1;
+                continue;
+            }
+
+            if kind.strips_block_comments() && c == '/' && chars.get(i + 1) == Some(&'*') {
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    i += 1;
+                }
+                i = (i + 2).min(chars.len());
+                pending_space = true;
+                continue;
+            }
+
+            if kind == MinifyKind::Js && c == '/' && chars.get(i + 1) == Some(&'/') {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                pending_space = true;
+                continue;
+            }
+
+            if kind.strips_markup_comments() && chars[i..].starts_with(&['<', '!', '-', '-']) {
+                i += 4;
+                while i < chars.len() && !chars[i..].starts_with(&['-', '-', '>']) {
+                    i += 1;
+                }
+                i = (i + 3).min(chars.len());
+                pending_space = true;
+                continue;
+            }
+
+            if c.is_whitespace() {
+                pending_space = true;
+                i += 1;
+                continue;
+            }
+
+            if pending_space && !out.is_empty() {
+                out.push(' ');
+            }
+            pending_space = false;
+            out.push(c);
+            i += 1;
+        }
+
+        out
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    create_asset_registry!(
+        populated_assets,
+        [(FERRIS_PNG, "../assets/ferris.png", "PNG", "Ferris the crab in PNG format")],
+        [(BOYKISSER_TXT, "../assets/boykisser.txt", "Classic boykisser character")]
+    );
+
+    include_image_asset!(pub FERRIS_PNG_IMAGE, "../assets/ferris.png", "PNG", "Ferris the crab, as a decoded image asset");
+
+    #[test]
+    fn test_include_image_asset_extracts_real_dimensions() {
+        assert_eq!(FERRIS_PNG_IMAGE_INFO.format, "PNG");
+        assert_eq!(FERRIS_PNG_IMAGE_INFO.width, 1);
+        assert_eq!(FERRIS_PNG_IMAGE_INFO.height, 1);
+        assert_eq!(FERRIS_PNG_IMAGE_INFO.size, FERRIS_PNG_IMAGE.len());
+    }
+
     #[test]
     fn test_art_access() {
         assert!(!art::rust::RUST_LOGO.is_empty());
@@ -341,6 +1028,22 @@ mod tests {
         assert_eq!(images::ICON_PNG_INFO.size, images::ICON_PNG.len());
     }
 
+    #[test]
+    fn test_fingerprinted_name_inserts_hash_before_extension() {
+        let hash = images::ICON_PNG_INFO.hash_hex();
+        assert_eq!(hash.len(), 16);
+        assert_eq!(
+            images::ICON_PNG_INFO.fingerprinted_name("icon.png"),
+            format!("icon-{hash}.png")
+        );
+
+        let text_hash = art::rust::RUST_LOGO_INFO.hash_hex();
+        assert_eq!(
+            art::rust::RUST_LOGO_INFO.fingerprinted_name("rust-logo.txt"),
+            format!("rust-logo-{text_hash}.txt")
+        );
+    }
+
     #[test]
     fn test_base64_encoding() {
         let data = b"Hello, World!";
@@ -348,6 +1051,28 @@ mod tests {
         assert!(!encoded.is_empty());
     }
 
+    #[test]
+    fn test_base64_round_trip() {
+        for data in [&b""[..], b"f", b"fo", b"foo", b"foob", b"fooba", b"foobar"] {
+            let encoded = utils::to_base64(data);
+            let decoded = utils::from_base64(&encoded).expect("valid base64 should decode");
+            assert_eq!(decoded, data);
+        }
+    }
+
+    #[test]
+    fn test_from_base64_rejects_invalid_input() {
+        assert!(utils::from_base64("abc").is_err());
+        assert!(utils::from_base64("ab=c").is_err());
+        assert!(utils::from_base64("ab!=").is_err());
+    }
+
+    #[test]
+    fn test_from_base64_rejects_padding_before_the_final_chunk() {
+        assert!(utils::from_base64("ab==cdef").is_err());
+        assert!(utils::from_base64("ab==").is_ok());
+    }
+
     #[test]
     fn test_mime_type_detection() {
         assert_eq!(utils::mime_type_from_extension("png"), "image/png");
@@ -364,4 +1089,106 @@ mod tests {
         let url = utils::create_data_url(data, "text/plain");
         assert!(url.starts_with("data:text/plain;base64,"));
     }
+
+    #[test]
+    fn test_data_url_round_trip() {
+        let data = b"round trip me";
+        let url = utils::create_data_url(data, "text/plain");
+        let (mime, decoded) = utils::parse_data_url(&url).expect("well-formed data URL should parse");
+        assert_eq!(mime, "text/plain");
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_parse_data_url_rejects_malformed_input() {
+        assert!(utils::parse_data_url("not-a-data-url").is_none());
+        assert!(utils::parse_data_url("data:text/plain,plain-not-base64").is_none());
+        assert!(utils::parse_data_url("data:text/plain;base64,ab==cdef").is_none());
+    }
+
+    #[test]
+    fn test_minify_strips_comments_and_whitespace() {
+        let css = "body {\n  /* comment */\n  color:  red;\n}\n";
+        let minified = utils::minify(css, utils::MinifyKind::Css);
+        assert_eq!(minified, "body { color: red; }");
+    }
+
+    #[test]
+    fn test_minify_preserves_quoted_strings() {
+        let js = "const s = \"a  b\\nc\"; /* drop me */ let x = 1;";
+        let minified = utils::minify(js, utils::MinifyKind::Js);
+        assert_eq!(minified, "const s = \"a  b\\nc\"; let x = 1;");
+    }
+
+    #[test]
+    fn test_registry_runtime_lookup() {
+        let registry = default_assets::Registry;
+        assert!(registry.get_binary_asset("MISSING").is_none());
+        assert!(registry.get_text_asset("MISSING").is_none());
+        assert!(registry.list_assets().is_empty());
+    }
+
+    #[test]
+    fn test_registry_runtime_lookup_with_assets() {
+        let registry = populated_assets::Registry;
+        assert_eq!(
+            registry.get_binary_asset("FERRIS_PNG"),
+            Some(populated_assets::FERRIS_PNG)
+        );
+        assert_eq!(
+            registry.get_text_asset("BOYKISSER_TXT"),
+            Some(populated_assets::BOYKISSER_TXT)
+        );
+        assert!(registry.get_binary_asset("MISSING").is_none());
+
+        let binary_assets = populated_assets::list_binary_assets();
+        assert_eq!(binary_assets.len(), 1);
+        assert_eq!(binary_assets[0].name, "FERRIS_PNG");
+
+        let text_assets = populated_assets::list_text_assets();
+        assert_eq!(text_assets.len(), 1);
+        assert_eq!(text_assets[0].name, "BOYKISSER_TXT");
+
+        let names = registry.list_assets();
+        assert_eq!(names.len(), 2);
+        assert!(names.contains(&"FERRIS_PNG".to_string()));
+        assert!(names.contains(&"BOYKISSER_TXT".to_string()));
+    }
+
+    #[test]
+    fn test_csp_hash_matches_known_vector() {
+        assert_eq!(
+            utils::csp_hash(b""),
+            "sha256-47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU="
+        );
+        assert_eq!(
+            utils::csp_hash(b"abc"),
+            "sha256-ungWv48Bz+pBQUDeXa4iI7ADYaOWF3qctBD/YfIAFa0="
+        );
+    }
+
+    #[test]
+    fn test_csp_hashes_for_preserves_order() {
+        let hashes = utils::csp_hashes_for(&[b"abc", b""]);
+        assert_eq!(
+            hashes,
+            vec![
+                "sha256-ungWv48Bz+pBQUDeXa4iI7ADYaOWF3qctBD/YfIAFa0=",
+                "sha256-47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_in_memory_provider_resolves_registered_assets() {
+        let mut provider = InMemoryAssetProvider::new();
+        assert!(provider.setup().is_ok());
+        provider.register("/icon.png", b"fake png".to_vec(), "image/png");
+
+        let (data, mime) = provider.resolve("/icon.png").expect("should be registered");
+        assert_eq!(data, b"fake png");
+        assert_eq!(mime, "image/png");
+        assert!(provider.resolve("/missing.png").is_none());
+    }
 }
+